@@ -48,18 +48,74 @@ pub use wonderbox_codegen::autoresolvable;
 
 use crate::internal::AutoResolvable;
 use std::any::Any;
+use std::cell::RefCell;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Condvar, Mutex};
 
 /// The IoC container
 #[derive(Default, Debug, Clone)]
 pub struct Container {
-    registered_types: HashMap<&'static str, Arc<dyn Any + Send + Sync>>,
-    registered_type_factories: HashMap<&'static str, Arc<dyn Any + Send + Sync>>,
+    registered_types: HashMap<RegistrationKey, Arc<dyn Any + Send + Sync>>,
+    registered_type_factories: HashMap<RegistrationKey, Arc<dyn Any + Send + Sync>>,
+    resolved_singletons: Arc<SingletonCache>,
+    parent: Option<Arc<Container>>,
+}
+
+/// Backing storage for [`Container::register_singleton`]: a singleton is either being constructed
+/// by some thread (`InProgress`) or already constructed and cached (`Ready`), so that concurrent
+/// callers for the same type never race each other into building (and returning) distinct
+/// instances. `ready` wakes up threads that are waiting on an in-progress construction to finish.
+#[derive(Default, Debug)]
+struct SingletonCache {
+    states: Mutex<HashMap<&'static str, SingletonState>>,
+    ready: Condvar,
+}
+
+#[derive(Debug)]
+enum SingletonState {
+    InProgress,
+    Ready(Arc<dyn Any + Send + Sync>),
+}
+
+thread_local! {
+    /// The `(type name, binding name)`s currently being resolved on this thread, innermost last.
+    /// Scoped to a thread (rather than stored on `Container`, which is cloned and shared across
+    /// threads for concurrent resolution) so that one thread's in-progress resolution is never
+    /// mistaken for a cycle by another thread resolving the same type concurrently.
+    static RESOLUTION_PATH: RefCell<Vec<RegistrationKey>> = RefCell::new(Vec::new());
+}
+
+/// Pops the most recently pushed type off the in-progress resolution path once its resolution
+/// (successful or not) is done, so that cycle detection only ever sees the path of types that are
+/// still being resolved.
+struct ResolutionPathGuard;
+
+impl Drop for ResolutionPathGuard {
+    fn drop(&mut self) {
+        RESOLUTION_PATH.with(|path| {
+            path.borrow_mut().pop();
+        });
+    }
 }
 
 type ImplementationFactory<T> = dyn Fn(&Container) -> T + Send + Sync;
 
+/// A type is registered under its type name and an optional binding name, so that
+/// [`register`](Container::register) and [`register_named`](Container::register_named)
+/// can coexist for the same `T`.
+type RegistrationKey = (&'static str, Option<&'static str>);
+
+/// The error returned when resolving a type registered via
+/// [`register_fallible`](Container::register_fallible), distinguishing an unregistered type from
+/// one whose factory failed to construct it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ResolveError<E> {
+    /// No fallible factory was registered for the requested type.
+    Unregistered,
+    /// A fallible factory was registered, but it failed to construct the value.
+    ConstructionFailed(E),
+}
+
 impl Container {
     /// Create a new empty [`Container`].
     pub fn new() -> Self {
@@ -106,6 +162,49 @@ impl Container {
         &mut self,
         implementation_factory: impl Fn(&Container) -> T + 'static + Send + Sync + Clone,
     ) -> &mut Self
+    where
+        T: 'static,
+    {
+        self.register_keyed(None, implementation_factory)
+    }
+
+    /// Register a function that returns the implementation of a type under a named binding,
+    /// so that multiple implementations of the same type (e.g. different `Box<dyn Trait>`s)
+    /// can be registered side by side.
+    ///
+    /// # Examples
+    /// ```
+    /// use wonderbox::Container;
+    ///
+    /// let mut container = Container::new();
+    /// container.register_named("primary", |_| "primary logger".to_string());
+    /// container.register_named("fallback", |_| "fallback logger".to_string());
+    ///
+    /// assert_eq!(
+    ///     container.try_resolve_named::<String>("primary"),
+    ///     Some("primary logger".to_string())
+    /// );
+    /// assert_eq!(
+    ///     container.try_resolve_named::<String>("fallback"),
+    ///     Some("fallback logger".to_string())
+    /// );
+    /// ```
+    pub fn register_named<T>(
+        &mut self,
+        name: &'static str,
+        implementation_factory: impl Fn(&Container) -> T + 'static + Send + Sync + Clone,
+    ) -> &mut Self
+    where
+        T: 'static,
+    {
+        self.register_keyed(Some(name), implementation_factory)
+    }
+
+    fn register_keyed<T>(
+        &mut self,
+        name: Option<&'static str>,
+        implementation_factory: impl Fn(&Container) -> T + 'static + Send + Sync + Clone,
+    ) -> &mut Self
     where
         T: 'static,
     {
@@ -114,7 +213,7 @@ impl Container {
             Box::new(move |container| implementation_factory(container))
         };
         self.registered_types.insert(
-            type_name::<T>(),
+            (type_name::<T>(), name),
             Arc::new(registered_implementation_factory),
         );
 
@@ -127,7 +226,7 @@ impl Container {
         });
 
         self.registered_type_factories.insert(
-            type_name::<Box<dyn Fn() -> T>>(),
+            (type_name::<Box<dyn Fn() -> T>>(), name),
             Arc::new(partially_applied_implementation_factory),
         );
 
@@ -182,6 +281,149 @@ impl Container {
         self
     }
 
+    /// Register a type while automatically resolving its dependencies, under a named binding.
+    /// See [`register_autoresolvable`](Container::register_autoresolvable) and
+    /// [`register_named`](Container::register_named).
+    pub fn register_autoresolvable_named<ResolvedType, RegisteredType>(
+        &mut self,
+        name: &'static str,
+        registration_fn: impl Fn(Option<ResolvedType>) -> RegisteredType + 'static + Send + Sync + Clone,
+    ) -> &mut Self
+    where
+        ResolvedType: AutoResolvable,
+        RegisteredType: 'static,
+    {
+        self.register_named(name, move |container| {
+            registration_fn(ResolvedType::try_resolve(container))
+        });
+        self
+    }
+
+    /// Register a factory that is invoked at most once. The first call to
+    /// [`try_resolve`](Container::try_resolve)`::<`[`Arc`]`<T>>()` runs the factory and stores the
+    /// produced [`Arc`], every subsequent resolution returns a clone of that same `Arc` instead of
+    /// constructing a new instance. Useful for shared state such as a connection pool or a config
+    /// object.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use wonderbox::Container;
+    ///
+    /// let mut container = Container::new();
+    /// container.register_singleton(|_| "expensive to construct".to_string());
+    ///
+    /// let first = container.try_resolve::<Arc<String>>().unwrap();
+    /// let second = container.try_resolve::<Arc<String>>().unwrap();
+    /// assert!(Arc::ptr_eq(&first, &second));
+    /// ```
+    pub fn register_singleton<T>(
+        &mut self,
+        factory: impl Fn(&Container) -> T + 'static + Send + Sync,
+    ) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let key = type_name::<T>();
+        self.register(move |container: &Container| -> Arc<T> {
+            let cache = &*container.resolved_singletons;
+            let mut states = cache.states.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            loop {
+                match states.get(key) {
+                    Some(SingletonState::Ready(value)) => {
+                        return downcast_singleton(Arc::clone(value), key);
+                    }
+                    // Another thread is already constructing this singleton: wait for it to
+                    // finish instead of racing it into building (and returning) a second instance.
+                    Some(SingletonState::InProgress) => {
+                        states = cache
+                            .ready
+                            .wait(states)
+                            .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    }
+                    None => {
+                        states.insert(key, SingletonState::InProgress);
+                        break;
+                    }
+                }
+            }
+            drop(states);
+
+            // `factory` may itself resolve another singleton on this same container, so the lock
+            // must not be held while it runs, or that nested resolution would deadlock trying to
+            // re-acquire it on this thread.
+            let value = Arc::new(factory(container));
+
+            cache
+                .states
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .insert(key, SingletonState::Ready(Arc::clone(&value) as Arc<dyn Any + Send + Sync>));
+            cache.ready.notify_all();
+
+            value
+        });
+        self
+    }
+
+    /// Register a factory that may fail to construct its value (e.g. it opens a file, parses
+    /// configuration, or connects to a resource). Resolve it with
+    /// [`try_resolve_result`](Container::try_resolve_result) to get the underlying error instead
+    /// of a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use wonderbox::{Container, ResolveError};
+    ///
+    /// let mut container = Container::new();
+    /// container.register_fallible(|_| "42".parse::<u32>());
+    ///
+    /// let resolved = container.try_resolve_result::<u32, std::num::ParseIntError>();
+    /// assert_eq!(resolved, Ok(42));
+    /// ```
+    pub fn register_fallible<T, E>(
+        &mut self,
+        factory: impl Fn(&Container) -> Result<T, E> + 'static + Send + Sync + Clone,
+    ) -> &mut Self
+    where
+        T: 'static,
+        E: 'static,
+    {
+        self.register(factory);
+        self
+    }
+
+    /// Creates a child container that overlays this container without mutating it. Registrations
+    /// made on the child are only visible through the child (and further scopes created from it);
+    /// anything not registered on the child falls through to this container. This is useful for
+    /// short-lived, request-scoped bindings (e.g. a current user or a request id) that should
+    /// shadow global bindings and be dropped once the scope ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use wonderbox::Container;
+    ///
+    /// let mut root = Container::new();
+    /// root.register(|_| "global".to_string());
+    ///
+    /// let mut request_scope = root.scope();
+    /// request_scope.register(|_| "request-scoped".to_string());
+    ///
+    /// assert_eq!(
+    ///     request_scope.try_resolve::<String>(),
+    ///     Some("request-scoped".to_string())
+    /// );
+    /// assert_eq!(root.try_resolve::<String>(), Some("global".to_string()));
+    /// ```
+    pub fn scope(&self) -> Self {
+        Self {
+            registered_types: HashMap::new(),
+            registered_type_factories: HashMap::new(),
+            resolved_singletons: Arc::new(SingletonCache::default()),
+            parent: Some(Arc::new(self.clone())),
+        }
+    }
+
     /// Register all the element from another container into this container.
     /// # Examples
     /// ```
@@ -210,6 +452,23 @@ impl Container {
     pub fn extend(&mut self, container: Container) -> &mut Self {
         self.registered_types
             .extend(container.registered_types.into_iter());
+        self.registered_type_factories
+            .extend(container.registered_type_factories.into_iter());
+
+        let other_singletons = container
+            .resolved_singletons
+            .states
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.resolved_singletons
+            .states
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend(other_singletons.iter().filter_map(|(&key, state)| match state {
+                SingletonState::Ready(value) => Some((key, SingletonState::Ready(Arc::clone(value)))),
+                SingletonState::InProgress => None,
+            }));
+
         self
     }
 
@@ -256,24 +515,129 @@ impl Container {
     where
         T: 'static,
     {
-        let key = type_name::<T>();
+        self.try_resolve_keyed(None)
+    }
+
+    /// Retrieves the implementation that was registered under the given name via
+    /// [`register_named`](Container::register_named).
+    /// # Errors
+    /// Returns `None` if no type was registered under that name.
+    /// # Examples
+    /// ```
+    /// use wonderbox::Container;
+    ///
+    /// let mut container = Container::new();
+    /// container.register_named("primary", |_| "primary".to_string());
+    ///
+    /// let resolved = container.try_resolve_named::<String>("primary");
+    /// assert_eq!(resolved, Some("primary".to_string()));
+    /// ```
+    pub fn try_resolve_named<T>(&self, name: &'static str) -> Option<T>
+    where
+        T: 'static,
+    {
+        self.try_resolve_keyed(Some(name))
+    }
+
+    /// Retrieves the implementation of a type registered via
+    /// [`register_fallible`](Container::register_fallible), distinguishing a type that was never
+    /// registered from one whose factory ran and failed.
+    /// # Errors
+    /// Returns [`ResolveError::Unregistered`] if no fallible factory was registered for `T`, or
+    /// [`ResolveError::ConstructionFailed`] with the underlying error if the factory ran but
+    /// failed.
+    /// # Examples
+    /// ```
+    /// use wonderbox::{Container, ResolveError};
+    ///
+    /// let mut container = Container::new();
+    /// container.register_fallible(|_| "not a number".parse::<u32>());
+    ///
+    /// let resolved = container.try_resolve_result::<u32, std::num::ParseIntError>();
+    /// assert!(matches!(resolved, Err(ResolveError::ConstructionFailed(_))));
+    /// ```
+    pub fn try_resolve_result<T, E>(&self) -> Result<T, ResolveError<E>>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        self.try_resolve::<Result<T, E>>()
+            .ok_or(ResolveError::Unregistered)?
+            .map_err(ResolveError::ConstructionFailed)
+    }
+
+    fn try_resolve_keyed<T>(&self, name: Option<&'static str>) -> Option<T>
+    where
+        T: 'static,
+    {
+        self.try_resolve_traced(name, false)
+    }
+
+    /// Resolves `T`, tracking the chain of `(type, binding name)`s currently being resolved *on
+    /// this thread* so that a type depending on itself (directly or transitively) is reported as
+    /// a readable cycle instead of overflowing the stack. Two differently-named bindings of the
+    /// same `T` don't count as a cycle of each other, since they're tracked by the full key, not
+    /// just the type name.
+    ///
+    /// A detected cycle only panics when `panic_on_cycle` is set: `resolve` sets it, since it
+    /// already panics on every other failure mode, but `try_resolve`/`try_resolve_named` must
+    /// honor their `Option`-returning, non-panicking contract, so they resolve with it unset and
+    /// get `None` back instead.
+    fn try_resolve_traced<T>(&self, name: Option<&'static str>, panic_on_cycle: bool) -> Option<T>
+    where
+        T: 'static,
+    {
+        let key: RegistrationKey = (type_name::<T>(), name);
+
+        let is_cycle = RESOLUTION_PATH.with(|path| path.borrow().contains(&key));
+        if is_cycle {
+            if !panic_on_cycle {
+                return None;
+            }
+            let cycle = RESOLUTION_PATH.with(|path| {
+                let mut cycle: Vec<_> = path.borrow().iter().map(|&(type_name, _)| type_name).collect();
+                cycle.push(key.0);
+                cycle.join(" -> ")
+            });
+            panic!(
+                "Wonderbox detected a circular dependency while resolving `{}`: {}\nHelp: {}",
+                key.0,
+                cycle,
+                self.resolution_failure_help()
+            );
+        }
+        RESOLUTION_PATH.with(|path| path.borrow_mut().push(key));
+        let _guard = ResolutionPathGuard;
+
         let resolvable_type = self
             .registered_types
-            .get(key)
-            .or_else(|| self.registered_type_factories.get(key))?;
-        let implementation_factory = resolvable_type
-            .downcast_ref::<Box<ImplementationFactory<T>>>()
-            .unwrap_or_else(|| {
-                panic!(
-                    "Internal error: Couldn't downcast internally stored registered type to resolved \
-                     type `{}`.\nYou've encountered a Wonderbox bug. Please consider opening an \
-                     issue at https://github.com/jnferner/wonderbox/issues/new\nAdditional info: {}",
-                    type_name::<T>(),
-                    self.resolution_failure_help()
-                )
-            });
-        let value = implementation_factory(self);
-        Some(value)
+            .get(&key)
+            .or_else(|| self.registered_type_factories.get(&key));
+        match resolvable_type {
+            Some(resolvable_type) => {
+                let implementation_factory = resolvable_type
+                    .downcast_ref::<Box<ImplementationFactory<T>>>()
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Internal error: Couldn't downcast internally stored registered type to \
+                             resolved type `{}`.\nYou've encountered a Wonderbox bug. Please consider \
+                             opening an issue at https://github.com/jnferner/wonderbox/issues/new\
+                             \nAdditional info: {}",
+                            type_name::<T>(),
+                            self.resolution_failure_help()
+                        )
+                    });
+                Some(implementation_factory(self))
+            }
+            // Not registered on this scope: fall through to the parent, if any. The parent pushes
+            // this same key again, so it is popped first to not be mistaken for a cycle.
+            None => {
+                drop(_guard);
+                self.parent
+                    .as_ref()
+                    .and_then(|parent| parent.try_resolve_traced(name, panic_on_cycle))
+            }
+        }
     }
 
     /// Retrieves the registered implementation of the specified type.
@@ -318,7 +682,7 @@ impl Container {
     where
         T: 'static,
     {
-        self.try_resolve::<T>().unwrap_or_else(|| {
+        self.try_resolve_traced::<T>(None, true).unwrap_or_else(|| {
             panic!(
                 "Wonderbox failed to resolve the type `{}`.\nHelp: {}",
                 type_name::<T>(),
@@ -359,7 +723,10 @@ impl Container {
     fn registered_type_names(&self) -> Vec<String> {
         self.registered_types
             .keys()
-            .map(|&key| String::from(key))
+            .map(|&(type_name, name)| match name {
+                Some(name) => format!("{} (named \"{}\")", type_name, name),
+                None => String::from(type_name),
+            })
             .collect()
     }
 }
@@ -412,6 +779,17 @@ fn type_name<T>() -> &'static str {
     unsafe { std::intrinsics::type_name::<T>() }
 }
 
+fn downcast_singleton<T: 'static>(value: Arc<dyn Any + Send + Sync>, key: &'static str) -> Arc<T> {
+    value.downcast::<T>().unwrap_or_else(|_| {
+        panic!(
+            "Internal error: Couldn't downcast internally stored singleton to resolved type \
+             `{}`.\nYou've encountered a Wonderbox bug. Please consider opening an issue at \
+             https://github.com/jnferner/wonderbox/issues/new",
+            key
+        )
+    })
+}
+
 #[doc(hidden)]
 pub mod internal {
     use super::*;
@@ -558,6 +936,223 @@ mod tests {
         assert!(resolved.is_some())
     }
 
+    #[test]
+    fn resolves_named_registration_independently_of_default_one() {
+        let mut container = Container::new();
+        container.register(|_| Box::new(FooImpl::new()) as Box<dyn Foo>);
+        container.register_named("fallback", |_| Box::new(FooImpl::new()) as Box<dyn Foo>);
+
+        let default_resolved = container.try_resolve::<Box<dyn Foo>>();
+        let named_resolved = container.try_resolve_named::<Box<dyn Foo>>("fallback");
+
+        assert!(default_resolved.is_some());
+        assert!(named_resolved.is_some());
+    }
+
+    #[test]
+    fn resolves_none_for_unregistered_name() {
+        let mut container = Container::new();
+        container.register_named("primary", |_| "foo".to_string());
+
+        let resolved = container.try_resolve_named::<String>("fallback");
+        assert!(resolved.is_none())
+    }
+
+    #[test]
+    fn singleton_is_constructed_only_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let construction_count = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+        {
+            let construction_count = Arc::clone(&construction_count);
+            container.register_singleton(move |_| {
+                construction_count.fetch_add(1, Ordering::SeqCst);
+                "singleton".to_string()
+            });
+        }
+
+        let first = container.try_resolve::<Arc<String>>().unwrap();
+        let second = container.try_resolve::<Arc<String>>().unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(construction_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn singleton_factory_can_resolve_a_different_singleton_without_deadlocking() {
+        struct ConnectionPool;
+        struct Config;
+
+        let mut container = Container::new();
+        container.register_singleton(|_| ConnectionPool);
+        container.register_singleton(|container: &Container| {
+            let _connection_pool = container.try_resolve::<Arc<ConnectionPool>>().unwrap();
+            Config
+        });
+
+        let resolved = container.try_resolve::<Arc<Config>>();
+        assert!(resolved.is_some());
+    }
+
+    #[test]
+    fn singleton_is_constructed_only_once_under_concurrent_resolution() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Barrier;
+        use std::thread;
+
+        let construction_count = Arc::new(AtomicUsize::new(0));
+        let mut container = Container::new();
+        {
+            let construction_count = Arc::clone(&construction_count);
+            container.register_singleton(move |_| {
+                construction_count.fetch_add(1, Ordering::SeqCst);
+                // Give every thread a chance to observe the singleton as not-yet-constructed
+                // before any of them finishes constructing it.
+                thread::sleep(std::time::Duration::from_millis(10));
+                "singleton".to_string()
+            });
+        }
+
+        let thread_count = 8;
+        let barrier = Arc::new(Barrier::new(thread_count));
+        let handles: Vec<_> = (0..thread_count)
+            .map(|_| {
+                let container = container.clone();
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    container.try_resolve::<Arc<String>>().unwrap()
+                })
+            })
+            .collect();
+        let resolved: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+        assert!(resolved.iter().all(|value| Arc::ptr_eq(value, &resolved[0])));
+        assert_eq!(construction_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn extend_carries_over_already_materialized_singletons() {
+        let mut first_container = Container::new();
+        first_container.register_singleton(|_| "foo".to_string());
+        let first = first_container.try_resolve::<Arc<String>>().unwrap();
+
+        let mut second_container = Container::new();
+        second_container.extend(first_container);
+
+        let resolved = second_container.try_resolve::<Arc<String>>().unwrap();
+        assert!(Arc::ptr_eq(&first, &resolved));
+    }
+
+    #[test]
+    #[should_panic(expected = "Wonderbox detected a circular dependency while resolving \
+                               `std::boxed::Box<dyn tests::Foo>`: \
+                               std::boxed::Box<dyn tests::Foo> -> std::boxed::Box<dyn tests::Bar> -> \
+                               std::boxed::Box<dyn tests::Foo>")]
+    fn detects_circular_dependency_instead_of_overflowing_the_stack() {
+        let mut container = Container::new();
+        container.register(|container: &Container| {
+            let _bar = container.resolve::<Box<dyn Bar>>();
+            Box::new(FooImpl::new()) as Box<dyn Foo>
+        });
+        container.register(|container: &Container| {
+            let _foo = container.resolve::<Box<dyn Foo>>();
+            Box::new(BarImpl::new("circular".to_string())) as Box<dyn Bar>
+        });
+
+        let _resolved = container.resolve::<Box<dyn Foo>>();
+    }
+
+    #[test]
+    fn try_resolve_returns_none_instead_of_panicking_on_a_circular_dependency() {
+        let mut container = Container::new();
+        container.register(|container: &Container| {
+            let _bar = container.try_resolve::<Box<dyn Bar>>();
+            Box::new(FooImpl::new()) as Box<dyn Foo>
+        });
+        container.register(|container: &Container| {
+            let _foo = container.try_resolve::<Box<dyn Foo>>();
+            Box::new(BarImpl::new("circular".to_string())) as Box<dyn Bar>
+        });
+
+        assert!(container.try_resolve::<Box<dyn Foo>>().is_some());
+    }
+
+    #[test]
+    fn resolving_a_different_named_binding_of_the_same_type_from_a_factory_is_not_a_cycle() {
+        let mut container = Container::new();
+        container.register_named("primary", |_| "primary".to_string());
+        container.register_named("decorated", |container: &Container| {
+            let primary = container.try_resolve_named::<String>("primary").unwrap();
+            format!("{}, decorated", primary)
+        });
+
+        let resolved = container.try_resolve_named::<String>("decorated");
+        assert_eq!(resolved, Some("primary, decorated".to_string()));
+    }
+
+    #[test]
+    fn scope_overrides_parent_registration_without_mutating_it() {
+        let mut root = Container::new();
+        root.register(|_| "global".to_string());
+
+        let mut scope = root.scope();
+        scope.register(|_| "scoped".to_string());
+
+        assert_eq!(scope.try_resolve::<String>(), Some("scoped".to_string()));
+        assert_eq!(root.try_resolve::<String>(), Some("global".to_string()));
+    }
+
+    #[test]
+    fn scope_falls_through_to_parent_for_unregistered_types() {
+        let mut root = Container::new();
+        root.register(|_| "global".to_string());
+
+        let scope = root.scope();
+
+        assert_eq!(scope.try_resolve::<String>(), Some("global".to_string()));
+    }
+
+    #[test]
+    fn extend_merges_registered_type_factories() {
+        let mut first_container = Container::new();
+
+        let mut second_container = Container::new();
+        second_container.register(|_| "foo".to_string());
+
+        first_container.extend(second_container);
+
+        let resolved = first_container.try_resolve::<Box<dyn Fn() -> String>>();
+        assert!(resolved.is_some());
+    }
+
+    #[test]
+    fn try_resolve_result_succeeds_for_a_fallible_factory_that_does_not_fail() {
+        let mut container = Container::new();
+        container.register_fallible(|_| "42".parse::<u32>());
+
+        let resolved = container.try_resolve_result::<u32, std::num::ParseIntError>();
+        assert_eq!(resolved, Ok(42));
+    }
+
+    #[test]
+    fn try_resolve_result_surfaces_the_construction_error() {
+        let mut container = Container::new();
+        container.register_fallible(|_| "not a number".parse::<u32>());
+
+        let resolved = container.try_resolve_result::<u32, std::num::ParseIntError>();
+        assert!(matches!(resolved, Err(ResolveError::ConstructionFailed(_))));
+    }
+
+    #[test]
+    fn try_resolve_result_distinguishes_unregistered_from_construction_failed() {
+        let container = Container::new();
+
+        let resolved = container.try_resolve_result::<u32, std::num::ParseIntError>();
+        assert_eq!(resolved, Err(ResolveError::Unregistered));
+    }
+
     #[test]
     fn resolves_type_from_merged_containers() {
         let mut first_container = Container::new();
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use wonderbox::internal::AutoResolvable;
+use wonderbox::Container;
+use wonderbox_codegen::resolve_dependencies;
+
+#[derive(Debug, Default)]
+struct Config {
+    loaded_count: usize,
+}
+
+#[resolve_dependencies]
+impl Config {
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[test]
+fn test() {
+    let mut container = Container::new();
+    container.register_singleton(|container: &Container| {
+        let mut config = Config::try_resolve(container).unwrap();
+        config.loaded_count += 1;
+        config
+    });
+
+    let first = container.try_resolve::<Arc<Config>>().unwrap();
+    let second = container.try_resolve::<Arc<Config>>().unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.loaded_count, 1);
+}
@@ -0,0 +1,27 @@
+use wonderbox::Container;
+use wonderbox_codegen::implementation;
+
+trait Foo {}
+
+#[derive(Debug, Default)]
+struct FooImpl {
+    stored_string: String,
+}
+
+#[implementation(Foo)]
+impl FooImpl {
+    fn new(stored_string: String) -> Self {
+        Self { stored_string }
+    }
+}
+
+impl Foo for FooImpl {}
+
+#[test]
+fn test() {
+    let mut container = Container::new();
+    container.register(|_| "foo".to_string());
+    container.register_autoresolvable(|foo: Option<Box<dyn Foo>>| foo.unwrap());
+
+    let _foo = container.resolve::<Box<dyn Foo>>();
+}
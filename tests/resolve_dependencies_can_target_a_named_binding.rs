@@ -0,0 +1,25 @@
+use wonderbox::Container;
+use wonderbox_codegen::resolve_dependencies;
+
+#[derive(Debug)]
+struct Logger {
+    destination: String,
+}
+
+#[resolve_dependencies]
+impl Logger {
+    fn new(#[named("fallback")] destination: String) -> Self {
+        Self { destination }
+    }
+}
+
+#[test]
+fn test() {
+    let mut container = Container::new();
+    container.register_named("primary", |_| "primary.log".to_string());
+    container.register_named("fallback", |_| "fallback.log".to_string());
+    container.register_autoresolvable(|logger: Option<Logger>| logger.unwrap());
+
+    let logger = container.resolve::<Logger>();
+    assert_eq!(logger.destination, "fallback.log");
+}
@@ -0,0 +1,28 @@
+use wonderbox::Container;
+use wonderbox_codegen::resolve_dependencies;
+
+#[derive(Debug, Default)]
+struct List {
+    capacity: usize,
+}
+
+#[resolve_dependencies(constructor = "with_capacity")]
+impl List {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self { capacity }
+    }
+}
+
+#[test]
+fn test() {
+    let mut container = Container::new();
+    container.register(|_| 4_usize);
+    container.register_autoresolvable(|list: Option<List>| list.unwrap());
+
+    let list = container.resolve::<List>();
+    assert_eq!(list.capacity, 4)
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use wonderbox::Container;
+use wonderbox_codegen::resolve_dependencies;
+
+#[derive(Debug)]
+struct Foo {
+    _bar: Arc<Bar>,
+}
+
+#[resolve_dependencies]
+impl Foo {
+    fn new(_bar: Arc<Bar>) -> Self {
+        Self { _bar }
+    }
+}
+
+#[derive(Debug)]
+struct Bar {
+    _foo: Arc<Foo>,
+}
+
+#[resolve_dependencies]
+impl Bar {
+    fn new(_foo: Arc<Foo>) -> Self {
+        Self { _foo }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Wonderbox detected a circular dependency")]
+fn test() {
+    let mut container = Container::new();
+    container.register_autoresolvable(|foo: Option<Foo>| Arc::new(foo.unwrap()) as Arc<Foo>);
+    container.register_autoresolvable(|bar: Option<Bar>| Arc::new(bar.unwrap()) as Arc<Bar>);
+
+    let _foo = container.resolve::<Arc<Foo>>();
+}
@@ -8,8 +8,9 @@ use crate::spanned::SpannedUnstable;
 use proc_macro::{Diagnostic, Level, TokenStream};
 use quote::quote;
 use syn::{
-    parse_macro_input, parse_quote, punctuated::Punctuated, token::Comma, AttributeArgs, FnArg,
-    FnDecl, ImplItem, ImplItemMethod, Item, ItemImpl, MethodSig, ReturnType, Type,
+    parse_macro_input, parse_quote, punctuated::Punctuated, token::Comma, Attribute,
+    AttributeArgs, FnArg, FnDecl, Ident, ImplItem, ImplItemMethod, Item, ItemImpl, Lit, Meta,
+    MetaNameValue, MethodSig, NestedMeta, ReturnType, Type,
 };
 
 type Result<T> = std::result::Result<T, Diagnostic>;
@@ -19,7 +20,10 @@ pub fn resolve_dependencies(attr: TokenStream, item: TokenStream) -> TokenStream
     let item = parse_macro_input!(item as Item);
     let attr = parse_macro_input!(attr as AttributeArgs);
 
-    let result = generate_autoresolvable_impl(&item);
+    let result =
+        parse_constructor_name(&attr).and_then(|constructor_name| {
+            generate_autoresolvable_impl(&item, constructor_name.as_deref())
+        });
 
     let emited_tokens = match result {
         Ok(token_stream) => token_stream,
@@ -34,7 +38,98 @@ pub fn resolve_dependencies(attr: TokenStream, item: TokenStream) -> TokenStream
     emited_tokens.into()
 }
 
-fn generate_autoresolvable_impl(item: &Item) -> Result<proc_macro2::TokenStream> {
+/// Binds a concrete type to a trait in the container: `#[implementation(Foo)]` over `impl
+/// FooImpl` generates, in addition to the `AutoResolvable` impl that [`resolve_dependencies`]
+/// would produce, an `AutoResolvable for Box<dyn Foo>` that boxes the resolved `FooImpl` as
+/// `Box<dyn Foo>` (the pattern tests otherwise perform by hand with
+/// `register_autoresolvable(|foo: Option<FooImpl>| Box::new(foo.unwrap()) as Box<dyn Foo>)`).
+#[proc_macro_attribute]
+pub fn implementation(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as Item);
+    let attr = parse_macro_input!(attr as AttributeArgs);
+
+    let result = parse_trait_ident(&attr).and_then(|trait_ident| {
+        generate_implementation_impl(trait_ident, &item)
+    });
+
+    let emited_tokens = match result {
+        Ok(token_stream) => token_stream,
+        Err(diagnostic) => {
+            diagnostic.emit();
+            quote! {
+                #item
+            }
+        }
+    };
+
+    emited_tokens.into()
+}
+
+fn parse_trait_ident(attr: &AttributeArgs) -> Result<&Ident> {
+    match attr.as_slice() {
+        [NestedMeta::Meta(Meta::Word(trait_ident))] => Ok(trait_ident),
+        [unexpected, ..] => Err(Diagnostic::spanned(
+            unexpected.span_unstable(),
+            Level::Error,
+            "Expected exactly one argument naming the trait to implement, e.g. \
+             `#[implementation(Foo)]`",
+        )),
+        [] => Err(Diagnostic::new(
+            Level::Error,
+            "Expected exactly one argument naming the trait to implement, e.g. \
+             `#[implementation(Foo)]`",
+        )),
+    }
+}
+
+fn generate_implementation_impl(
+    trait_ident: &Ident,
+    item: &Item,
+) -> Result<proc_macro2::TokenStream> {
+    let self_ty = &parse_item_impl(item)?.self_ty;
+    let autoresolvable_impl = generate_autoresolvable_impl(item, None)?;
+
+    Ok(quote! {
+        #autoresolvable_impl
+
+        impl wonderbox::internal::AutoResolvable for Box<dyn #trait_ident> {
+            fn resolve(container: &wonderbox::Container) -> Option<Self> {
+                let implementation =
+                    <#self_ty as wonderbox::internal::AutoResolvable>::resolve(container)?;
+                Some(Box::new(implementation) as Box<dyn #trait_ident>)
+            }
+        }
+    })
+}
+
+/// Parses an optional `constructor = "method_name"` argument off the attribute, e.g.
+/// `#[resolve_dependencies(constructor = "with_capacity")]`, which selects a specific
+/// constructor instead of requiring the impl block to have exactly one.
+fn parse_constructor_name(attr: &AttributeArgs) -> Result<Option<String>> {
+    let arg = match attr.first() {
+        Some(arg) => arg,
+        None => return Ok(None),
+    };
+
+    match arg {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            ident,
+            lit: Lit::Str(constructor_name),
+            ..
+        })) if ident == "constructor" => Ok(Some(constructor_name.value())),
+        _ => Err(Diagnostic::spanned(
+            arg.span_unstable(),
+            Level::Error,
+            "Expected `constructor = \"method_name\"`, e.g. \
+             `#[resolve_dependencies(constructor = \"new\")]`",
+        )),
+    }
+}
+
+fn generate_autoresolvable_impl(
+    item: &Item,
+    constructor_name: Option<&str>,
+) -> Result<proc_macro2::TokenStream> {
     let item = parse_item_impl(item)?;
 
     validate_item_impl(&item);
@@ -43,20 +138,11 @@ fn generate_autoresolvable_impl(item: &Item) -> Result<proc_macro2::TokenStream>
 
     let constructors = parse_constructors(&item);
 
-    if constructors.len() != 1 {
-        let error_message = format!("Expected one constructor, found {}", constructors.len());
-        return Err(Diagnostic::spanned(
-            item.span_unstable(),
-            Level::Error,
-            error_message,
-        ));
-    }
-
-    let constructor = constructors.first().unwrap();
+    let constructor = find_constructor(&item, &constructors, constructor_name)?;
 
-    let constructor_argument_types = parse_constructor_argument_types(constructor)?;
+    let constructor_arguments = parse_constructor_arguments(constructor)?;
 
-    let resolutions = generate_type_resolutions(&constructor_argument_types);
+    let resolutions = generate_type_resolutions(&constructor_arguments);
 
     let (impl_generics, type_generics, where_clause) = item.generics.split_for_impl();
     let ident = &constructor.ident;
@@ -104,6 +190,41 @@ fn validate_item_impl(item_impl: &ItemImpl) -> Result<()> {
 
 type FunctionArguments = Punctuated<FnArg, Comma>;
 
+/// Picks the constructor to generate `AutoResolvable::resolve` from: the one named
+/// `constructor_name` if given, otherwise the single constructor found (erroring if there isn't
+/// exactly one).
+fn find_constructor<'a>(
+    item_impl: &ItemImpl,
+    constructors: &[&'a MethodSig],
+    constructor_name: Option<&str>,
+) -> Result<&'a MethodSig> {
+    match constructor_name {
+        Some(name) => constructors
+            .iter()
+            .find(|constructor| constructor.ident == name)
+            .copied()
+            .ok_or_else(|| {
+                let error_message = format!(
+                    "No constructor named `{}` returning `Self` was found on this impl block",
+                    name
+                );
+                Diagnostic::spanned(item_impl.span_unstable(), Level::Error, error_message)
+            }),
+        None => {
+            if constructors.len() != 1 {
+                let error_message =
+                    format!("Expected one constructor, found {}", constructors.len());
+                return Err(Diagnostic::spanned(
+                    item_impl.span_unstable(),
+                    Level::Error,
+                    error_message,
+                ));
+            }
+            Ok(constructors[0])
+        }
+    }
+}
+
 fn parse_constructors(item_impl: &ItemImpl) -> Vec<&MethodSig> {
     item_impl
         .items
@@ -141,14 +262,24 @@ fn has_no_self_parameter(function: &FnDecl) -> bool {
     }
 }
 
-fn parse_constructor_argument_types(constructor: &MethodSig) -> Result<Vec<&Type>> {
+/// A constructor argument together with the name it should be resolved under, if it carries a
+/// `#[named("...")]` attribute (see [`parse_named_attribute`]).
+struct ConstructorArgument<'a> {
+    ty: &'a Type,
+    name: Option<String>,
+}
+
+fn parse_constructor_arguments(constructor: &MethodSig) -> Result<Vec<ConstructorArgument>> {
     constructor
         .decl
         .inputs
         .iter()
         .map(|arg| match arg {
             FnArg::SelfRef(_) | FnArg::SelfValue(_) => unreachable!(),
-            FnArg::Captured(arg) => Ok(&arg.ty),
+            FnArg::Captured(arg) => Ok(ConstructorArgument {
+                ty: &arg.ty,
+                name: parse_named_attribute(&arg.attrs)?,
+            }),
             _ => Err(Diagnostic::spanned(
                 arg.span_unstable(),
                 Level::Error,
@@ -158,12 +289,52 @@ fn parse_constructor_argument_types(constructor: &MethodSig) -> Result<Vec<&Type
         .collect()
 }
 
-fn generate_type_resolutions(types: &[&Type]) -> Punctuated<proc_macro2::TokenStream, Comma> {
-    types
+/// Parses an optional `#[named("...")]` attribute off a constructor argument, e.g.
+/// `fn new(#[named("primary")] dependency: Arc<dyn Dependency>) -> Self`, which resolves that
+/// argument from the binding registered under that name via
+/// [`register_named`](wonderbox::Container::register_named) instead of the default one.
+fn parse_named_attribute(attrs: &[Attribute]) -> Result<Option<String>> {
+    let attr = match attrs.iter().find(|attr| attr.path.is_ident("named")) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    let invalid_attribute = || {
+        Diagnostic::spanned(
+            attr.span_unstable(),
+            Level::Error,
+            "Expected `#[named(\"binding_name\")]`",
+        )
+    };
+
+    match attr.interpret_meta() {
+        Some(Meta::List(list)) => match list.nested.first() {
+            Some(pair) => match pair.value() {
+                NestedMeta::Literal(Lit::Str(name)) if list.nested.len() == 1 => {
+                    Ok(Some(name.value()))
+                }
+                _ => Err(invalid_attribute()),
+            },
+            None => Err(invalid_attribute()),
+        },
+        _ => Err(invalid_attribute()),
+    }
+}
+
+fn generate_type_resolutions(
+    arguments: &[ConstructorArgument],
+) -> Punctuated<proc_macro2::TokenStream, Comma> {
+    arguments
         .iter()
-        .map(|type_| {
-            quote! {
-                container.resolve::<#type_>()?
+        .map(|argument| {
+            let type_ = argument.ty;
+            match &argument.name {
+                Some(name) => quote! {
+                    container.try_resolve_named::<#type_>(#name)?
+                },
+                None => quote! {
+                    container.resolve::<#type_>()?
+                },
             }
         })
         .collect()